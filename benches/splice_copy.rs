@@ -0,0 +1,30 @@
+//! Compares `splice_copy` against the `small-copy-fastpath` feature for short `src` slices.
+//!
+//! Run with the feature enabled to exercise the fast path:
+//!
+//! ```sh
+//! cargo bench --bench splice_copy --features small-copy-fastpath
+//! ```
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_splice_copy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("splice_copy_small");
+
+    for len in 1..=32usize {
+        let src: Vec<u8> = (0..len as u8).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(len), &src, |b, src| {
+            b.iter(|| {
+                let mut dest = vec![0u8; 64];
+                splice::splice_copy(&mut dest, 32, src);
+                dest
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_splice_copy);
+criterion_main!(benches);
@@ -18,12 +18,72 @@
 //!
 //! assert_eq!(dest, vec![1, 2, 5, 6, 3, 4]);
 //! ```
+//!
+//! # Features
+//!
+//! * `small-copy-fastpath` — replaces the `memcpy` used by [`splice_copy`]/[`try_splice_copy`]
+//!   with a branch-light fixed-size double-copy for short `src` slices (4 to 32 bytes), which
+//!   is usually faster than the generic `memcpy` for the handful of elements a typical splice
+//!   inserts.
 
+use std::error;
+use std::fmt;
+use std::mem;
+use std::ops::Range;
 use std::ptr;
 
+/// The error type returned by the `try_splice_*` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpliceError {
+    /// The given index or range was out of bounds of the destination Vec.
+    IndexOutOfBounds {
+        /// The index that was given.
+        index: usize,
+        /// The length of the destination Vec at the time.
+        len: usize,
+    },
+    /// Combining the lengths of the destination and source would overflow `usize`, or would
+    /// produce a Vec with more than `isize::MAX` elements.
+    CapacityOverflow,
+}
+
+impl fmt::Display for SpliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SpliceError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds of a Vec with length {}", index, len)
+            }
+            SpliceError::CapacityOverflow => write!(f, "capacity overflow"),
+        }
+    }
+}
+
+impl error::Error for SpliceError {}
+
+/// Checks that `index` is in bounds of a Vec of length `dest_len`, and that splicing in
+/// `src_len` more elements won't overflow `usize` or exceed `isize::MAX` elements.
+fn check_splice_bounds(dest_len: usize, index: usize, src_len: usize) -> Result<(), SpliceError> {
+    if index > dest_len {
+        return Err(SpliceError::IndexOutOfBounds { index, len: dest_len });
+    }
+
+    let new_len = dest_len.checked_add(src_len).ok_or(SpliceError::CapacityOverflow)?;
+
+    if new_len > isize::MAX as usize {
+        return Err(SpliceError::CapacityOverflow);
+    }
+
+    Ok(())
+}
+
 /// Clone the contents of the given slice into the given Vec at the given index, shifting all
 /// existing elements after that position to the right.
 ///
+/// If one of the calls to `T::clone` panics partway through, `dest` is left in a consistent
+/// state: the tail elements that were already shifted to make room are moved back to close
+/// whatever part of the gap didn't get filled in, so no element of `dest` is read, dropped, or
+/// counted twice.
+///
 /// # Panics
 ///
 /// Panics if `index` is out of bounds of `dest`.
@@ -39,10 +99,73 @@ use std::ptr;
 /// assert_eq!(dest, vec!["one", "three", "four", "two"]);
 /// ```
 pub fn splice_clone<T: Clone>(dest: &mut Vec<T>, index: usize, src: &[T]) {
-    assert!(index <= dest.len(), "index of out of bounds of the Vec");
+    try_splice_clone(dest, index, src).expect("index of out of bounds of the Vec");
+}
+
+/// Tries to clone the contents of the given slice into the given Vec at the given index,
+/// shifting all existing elements after that position to the right.
+///
+/// Returns [`SpliceError::IndexOutOfBounds`] if `index` is out of bounds of `dest`, or
+/// [`SpliceError::CapacityOverflow`] if the combined length would overflow `usize` or exceed
+/// `isize::MAX` elements, instead of panicking.
+///
+/// If one of the calls to `T::clone` panics partway through, `dest` is left in a consistent
+/// state: the tail elements that were already shifted to make room are moved back to close
+/// whatever part of the gap didn't get filled in, so no element of `dest` is read, dropped, or
+/// counted twice.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest: Vec<String> = vec!["one".into(), "two".into()];
+/// let src: Vec<String> = vec!["three".into(), "four".into()];
+///
+/// splice::try_splice_clone(&mut dest, 1, &src).unwrap();
+///
+/// assert_eq!(dest, vec!["one", "three", "four", "two"]);
+/// ```
+pub fn try_splice_clone<T: Clone>(
+    dest: &mut Vec<T>,
+    index: usize,
+    src: &[T],
+) -> Result<(), SpliceError> {
+    check_splice_bounds(dest.len(), index, src.len())?;
 
     dest.reserve(src.len());
 
+    // Guards against `T::clone` panicking partway through the loop below. While the guard is
+    // alive, `dest`'s real length is still its original (pre-splice) length, even though the
+    // tail has already been memmoved out of the way and some of the gap may already hold cloned
+    // elements. If we unwind, compact the partially-filled gap by moving the tail back to meet
+    // however many elements were actually cloned, and fix up `dest`'s length to match, so no
+    // element is left uninitialized, duplicated, or dropped twice.
+    struct Guard<T> {
+        dest: *mut Vec<T>,
+        index: usize,
+        gap: usize,
+        written: usize,
+    }
+
+    impl<T> Drop for Guard<T> {
+        fn drop(&mut self) {
+            unsafe {
+                let dest = &mut *self.dest;
+                let base_len = dest.len();
+                let dest_ptr = dest.as_mut_ptr().add(self.index);
+                let shift_len = base_len - self.index;
+                let unfilled = self.gap - self.written;
+
+                if unfilled > 0 {
+                    let tail_ptr = dest_ptr.add(self.gap);
+                    let compact_ptr = dest_ptr.add(self.written);
+                    ptr::copy(tail_ptr, compact_ptr, shift_len);
+                }
+
+                dest.set_len(self.index + self.written + shift_len);
+            }
+        }
+    }
+
     unsafe {
         let dest_ptr = dest.as_mut_ptr().offset(index as isize);
         let shift_ptr = dest_ptr.offset(src.len() as isize);
@@ -50,13 +173,24 @@ pub fn splice_clone<T: Clone>(dest: &mut Vec<T>, index: usize, src: &[T]) {
 
         ptr::copy(dest_ptr, shift_ptr, shift_len);
 
+        let mut guard = Guard {
+            dest: dest as *mut Vec<T>,
+            index,
+            gap: src.len(),
+            written: 0,
+        };
+
         for (idx, elem) in src.iter().enumerate() {
             ptr::write(dest_ptr.offset(idx as isize), elem.clone());
+            guard.written = idx + 1;
         }
 
         let len = dest.len() + src.len();
+        mem::forget(guard);
         dest.set_len(len);
     }
+
+    Ok(())
 }
 
 /// Copies the contents of the given slice into the given Vec at the given index, shifting all
@@ -77,7 +211,32 @@ pub fn splice_clone<T: Clone>(dest: &mut Vec<T>, index: usize, src: &[T]) {
 /// assert_eq!(dest, vec![1, 2, 5, 6, 3, 4]);
 /// ```
 pub fn splice_copy<T: Copy>(dest: &mut Vec<T>, index: usize, src: &[T]) {
-    assert!(index <= dest.len(), "index of out of bounds of the Vec");
+    try_splice_copy(dest, index, src).expect("index of out of bounds of the Vec");
+}
+
+/// Tries to copy the contents of the given slice into the given Vec at the given index,
+/// shifting all existing elements after that position to the right.
+///
+/// Returns [`SpliceError::IndexOutOfBounds`] if `index` is out of bounds of `dest`, or
+/// [`SpliceError::CapacityOverflow`] if the combined length would overflow `usize` or exceed
+/// `isize::MAX` elements, instead of panicking.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest = vec![1u8, 2, 3, 4];
+/// let src = vec![5u8, 6];
+///
+/// splice::try_splice_copy(&mut dest, 2, &src).unwrap();
+///
+/// assert_eq!(dest, vec![1, 2, 5, 6, 3, 4]);
+/// ```
+pub fn try_splice_copy<T: Copy>(
+    dest: &mut Vec<T>,
+    index: usize,
+    src: &[T],
+) -> Result<(), SpliceError> {
+    check_splice_bounds(dest.len(), index, src.len())?;
 
     dest.reserve(src.len());
 
@@ -87,11 +246,185 @@ pub fn splice_copy<T: Copy>(dest: &mut Vec<T>, index: usize, src: &[T]) {
         let shift_len = dest.len() - index;
 
         ptr::copy(dest_ptr, shift_ptr, shift_len);
+
+        #[cfg(feature = "small-copy-fastpath")]
+        copy_small_fastpath(src.as_ptr(), dest_ptr, src.len());
+        #[cfg(not(feature = "small-copy-fastpath"))]
         ptr::copy_nonoverlapping(src.as_ptr(), dest_ptr, src.len());
 
         let len = dest.len() + src.len();
         dest.set_len(len);
     }
+
+    Ok(())
+}
+
+/// A branch-light replacement for `ptr::copy_nonoverlapping` used by [`try_splice_copy`] when
+/// the `small-copy-fastpath` feature is enabled and `src` is short.
+///
+/// For a region of `n` bytes where `block <= n <= 2 * block`, two fixed-size `block`-byte
+/// unconditional copies fully cover the region (one from the front, one ending at the tail) and
+/// overlap in the middle, which avoids the per-call setup of the generic `memcpy` that
+/// `copy_nonoverlapping` lowers to for a handful of bytes. Falls back to a single
+/// `copy_nonoverlapping` call for regions too large for any bucket below.
+#[cfg(feature = "small-copy-fastpath")]
+fn copy_small_fastpath<T>(src: *const T, dst: *mut T, len: usize) {
+    fn double_copy(src: *const u8, dst: *mut u8, n: usize, block: usize) {
+        unsafe {
+            ptr::copy_nonoverlapping(src, dst, block);
+            ptr::copy_nonoverlapping(src.add(n - block), dst.add(n - block), block);
+        }
+    }
+
+    unsafe {
+        let byte_len = len * mem::size_of::<T>();
+        let src = src as *const u8;
+        let dst = dst as *mut u8;
+
+        match byte_len {
+            0 => {}
+            1..=3 => ptr::copy_nonoverlapping(src, dst, byte_len),
+            4..=7 => double_copy(src, dst, byte_len, 4),
+            8..=16 => double_copy(src, dst, byte_len, 8),
+            17..=32 => double_copy(src, dst, byte_len, 16),
+            _ => ptr::copy_nonoverlapping(src, dst, byte_len),
+        }
+    }
+}
+
+/// Clones the contents of several slices into the given Vec at the given index, shifting all
+/// existing elements after that position to the right.
+///
+/// This is equivalent to calling [`splice_clone`] once per slice in `srcs`, advancing the index
+/// past each slice as it's inserted so the slices keep their order, but the tail of
+/// `dest` is only shifted once, rather than once per slice.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds of `dest`.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest: Vec<String> = vec!["one".into(), "two".into()];
+/// let three: Vec<String> = vec!["three".into()];
+/// let four: Vec<String> = vec!["four".into(), "five".into()];
+///
+/// splice::splice_clone_slices(&mut dest, 1, &[&three, &four]);
+///
+/// assert_eq!(dest, vec!["one", "three", "four", "five", "two"]);
+/// ```
+pub fn splice_clone_slices<T: Clone>(dest: &mut Vec<T>, index: usize, srcs: &[&[T]]) {
+    assert!(index <= dest.len(), "index of out of bounds of the Vec");
+
+    let total_len: usize = srcs.iter().map(|s| s.len()).sum();
+
+    dest.reserve(total_len);
+
+    // Same unwind guard as `try_splice_clone`, but `written` counts across every slice in
+    // `srcs` rather than a single one, since it's all being written into one contiguous gap.
+    struct Guard<T> {
+        dest: *mut Vec<T>,
+        index: usize,
+        gap: usize,
+        written: usize,
+    }
+
+    impl<T> Drop for Guard<T> {
+        fn drop(&mut self) {
+            unsafe {
+                let dest = &mut *self.dest;
+                let base_len = dest.len();
+                let dest_ptr = dest.as_mut_ptr().add(self.index);
+                let shift_len = base_len - self.index;
+                let unfilled = self.gap - self.written;
+
+                if unfilled > 0 {
+                    let tail_ptr = dest_ptr.add(self.gap);
+                    let compact_ptr = dest_ptr.add(self.written);
+                    ptr::copy(tail_ptr, compact_ptr, shift_len);
+                }
+
+                dest.set_len(self.index + self.written + shift_len);
+            }
+        }
+    }
+
+    unsafe {
+        let dest_ptr = dest.as_mut_ptr().offset(index as isize);
+        let shift_ptr = dest_ptr.offset(total_len as isize);
+        let shift_len = dest.len() - index;
+
+        ptr::copy(dest_ptr, shift_ptr, shift_len);
+
+        let mut guard = Guard {
+            dest: dest as *mut Vec<T>,
+            index,
+            gap: total_len,
+            written: 0,
+        };
+
+        let mut offset = 0usize;
+        for src in srcs {
+            for (idx, elem) in src.iter().enumerate() {
+                ptr::write(dest_ptr.offset((offset + idx) as isize), elem.clone());
+                guard.written = offset + idx + 1;
+            }
+            offset += src.len();
+        }
+
+        let len = dest.len() + total_len;
+        mem::forget(guard);
+        dest.set_len(len);
+    }
+}
+
+/// Copies the contents of several slices into the given Vec at the given index, shifting all
+/// existing elements after that position to the right.
+///
+/// This is equivalent to calling [`splice_copy`] once per slice in `srcs`, advancing the index
+/// past each slice as it's inserted so the slices keep their order, but the tail of
+/// `dest` is only shifted once, rather than once per slice. This avoids the O(n·k) cost of
+/// shifting the tail of `dest` for each of the `k` slices being inserted.
+///
+/// # Panics
+///
+/// Panics if `index` is out of bounds of `dest`.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest = vec![1u8, 2, 7, 8];
+/// let mid = vec![3u8, 4];
+/// let more = vec![5u8, 6];
+///
+/// splice::splice_copy_slices(&mut dest, 2, &[&mid, &more]);
+///
+/// assert_eq!(dest, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+pub fn splice_copy_slices<T: Copy>(dest: &mut Vec<T>, index: usize, srcs: &[&[T]]) {
+    assert!(index <= dest.len(), "index of out of bounds of the Vec");
+
+    let total_len: usize = srcs.iter().map(|s| s.len()).sum();
+
+    dest.reserve(total_len);
+
+    unsafe {
+        let dest_ptr = dest.as_mut_ptr().offset(index as isize);
+        let shift_ptr = dest_ptr.offset(total_len as isize);
+        let shift_len = dest.len() - index;
+
+        ptr::copy(dest_ptr, shift_ptr, shift_len);
+
+        let mut offset = 0isize;
+        for src in srcs {
+            ptr::copy_nonoverlapping(src.as_ptr(), dest_ptr.offset(offset), src.len());
+            offset += src.len() as isize;
+        }
+
+        let len = dest.len() + total_len;
+        dest.set_len(len);
+    }
 }
 
 /// Moves the contents of the source Vec into the destination Vec at the given index, shifting all
@@ -134,7 +467,29 @@ pub fn splice_copy<T: Copy>(dest: &mut Vec<T>, index: usize, src: &[T]) {
 /// assert!(sub_src.is_empty());
 /// ```
 pub fn splice<T>(dest: &mut Vec<T>, index: usize, src: &mut Vec<T>) {
-    assert!(index <= dest.len(), "index of out of bounds of the Vec");
+    try_splice(dest, index, src).expect("index of out of bounds of the Vec");
+}
+
+/// Tries to move the contents of the source Vec into the destination Vec at the given index,
+/// shifting all existing elements after that position to the right and leaving `src` empty.
+///
+/// Returns [`SpliceError::IndexOutOfBounds`] if `index` is out of bounds of `dest`, or
+/// [`SpliceError::CapacityOverflow`] if the combined length would overflow `usize` or exceed
+/// `isize::MAX` elements, instead of panicking.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest = vec![1u8, 2, 3, 4];
+/// let mut src = vec![5u8, 6];
+///
+/// splice::try_splice(&mut dest, 2, &mut src).unwrap();
+///
+/// assert_eq!(dest, vec![1, 2, 5, 6, 3, 4]);
+/// assert!(src.is_empty());
+/// ```
+pub fn try_splice<T>(dest: &mut Vec<T>, index: usize, src: &mut Vec<T>) -> Result<(), SpliceError> {
+    check_splice_bounds(dest.len(), index, src.len())?;
 
     dest.reserve(src.len());
 
@@ -150,6 +505,357 @@ pub fn splice<T>(dest: &mut Vec<T>, index: usize, src: &mut Vec<T>) {
         dest.set_len(len);
         src.set_len(0);
     }
+
+    Ok(())
+}
+
+/// Moves the elements in `src` so that they start at `dest_index`, shifting the elements
+/// between the two positions to fill the vacated region.
+///
+/// Unlike the other functions in this crate, this operates on a single Vec: the elements being
+/// relocated and the elements being shifted out of their way live in the same allocation, so the
+/// shift can't be expressed as a copy between two borrows, and `src` and the destination region
+/// can overlap. This is implemented as a triple-reversal rotation of the affected span (the same
+/// trick `[T]::rotate_left`/`rotate_right` use), so it needs no scratch buffer of its own,
+/// unlike the other functions in this crate which stage their moved elements in `dest`'s spare
+/// capacity.
+///
+/// # Panics
+///
+/// Panics if `src` is out of bounds of `v`, or if `dest_index` is out of bounds of `v` once
+/// `src` has been removed.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut v = vec![1, 2, 3, 4, 5, 6];
+///
+/// splice::splice_within(&mut v, 1..3, 4);
+///
+/// assert_eq!(v, vec![1, 4, 5, 6, 2, 3]);
+/// ```
+///
+/// Moving a range earlier in the Vec works the same way:
+///
+/// ```rust
+/// let mut v = vec![1, 2, 3, 4, 5, 6];
+///
+/// splice::splice_within(&mut v, 3..5, 1);
+///
+/// assert_eq!(v, vec![1, 4, 5, 2, 3, 6]);
+/// ```
+pub fn splice_within<T: Copy>(v: &mut Vec<T>, src: Range<usize>, dest_index: usize) {
+    assert!(src.start <= src.end, "src range start is after its end");
+    assert!(src.end <= v.len(), "src range is out of bounds of the Vec");
+
+    let src_len = src.end - src.start;
+
+    assert!(
+        dest_index <= v.len() - src_len,
+        "dest_index is out of bounds of the Vec"
+    );
+
+    if src_len == 0 || dest_index == src.start {
+        return;
+    }
+
+    // Reverses the `len` elements starting at `base` in place.
+    unsafe fn reverse<T>(base: *mut T, len: usize) {
+        if len < 2 {
+            return;
+        }
+
+        let mut i = 0;
+        let mut j = len - 1;
+
+        while i < j {
+            ptr::swap(base.add(i), base.add(j));
+            i += 1;
+            j -= 1;
+        }
+    }
+
+    unsafe {
+        let base = v.as_mut_ptr();
+
+        if dest_index < src.start {
+            // moving earlier: rotate [dest_index, src.end) left by `gap_len` so that `src`
+            // (the last `src_len` elements of that span) ends up at the front, at dest_index.
+            let gap_len = src.start - dest_index;
+
+            reverse(base.add(dest_index), gap_len);
+            reverse(base.add(src.start), src_len);
+            reverse(base.add(dest_index), gap_len + src_len);
+        } else {
+            // moving later: rotate [src.start, dest_index + src_len) left by `src_len` so that
+            // `src` (the first `src_len` elements of that span) ends up at the back, at
+            // dest_index.
+            let shift_len = dest_index - src.start;
+
+            reverse(base.add(src.start), src_len);
+            reverse(base.add(src.end), shift_len);
+            reverse(base.add(src.start), src_len + shift_len);
+        }
+    }
+}
+
+/// Removes the elements in `range` from `dest`, moves the contents of `src` into their place,
+/// and returns the removed elements as a new Vec, leaving `src` empty.
+///
+/// This matches the semantics of `Vec::splice` from the standard library, but with the efficient
+/// move-from-another-Vec behavior the rest of this crate specializes in, instead of consuming an
+/// arbitrary iterator.
+///
+/// # Panics
+///
+/// Panics if `range.end` is out of bounds of `dest`, or if `range.start > range.end`.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest = vec![1, 2, 3, 4, 5];
+/// let mut src = vec![10, 11];
+///
+/// let removed = splice::splice_replace(&mut dest, 1..3, &mut src);
+///
+/// assert_eq!(dest, vec![1, 10, 11, 4, 5]);
+/// assert_eq!(removed, vec![2, 3]);
+/// assert!(src.is_empty());
+/// ```
+pub fn splice_replace<T>(dest: &mut Vec<T>, range: Range<usize>, src: &mut Vec<T>) -> Vec<T> {
+    assert!(range.start <= range.end, "range start is after its end");
+    assert!(range.end <= dest.len(), "range is out of bounds of the Vec");
+
+    let range_len = range.end - range.start;
+    let src_len = src.len();
+
+    if src_len > range_len {
+        dest.reserve(src_len - range_len);
+    }
+
+    let mut removed: Vec<T> = Vec::with_capacity(range_len);
+
+    unsafe {
+        let dest_ptr = dest.as_mut_ptr();
+        let range_ptr = dest_ptr.add(range.start);
+
+        ptr::copy_nonoverlapping(range_ptr, removed.as_mut_ptr(), range_len);
+        removed.set_len(range_len);
+
+        let tail_ptr = dest_ptr.add(range.end);
+        let tail_len = dest.len() - range.end;
+        let new_tail_ptr = dest_ptr.add(range.start + src_len);
+
+        ptr::copy(tail_ptr, new_tail_ptr, tail_len);
+        ptr::copy_nonoverlapping(src.as_ptr(), range_ptr, src_len);
+
+        let new_len = dest.len() - range_len + src_len;
+        dest.set_len(new_len);
+        src.set_len(0);
+    }
+
+    removed
+}
+
+/// Removes the elements in `range` from `dest`, copies the contents of `src` into their place,
+/// and returns the removed elements as a new Vec, leaving `src` intact.
+///
+/// # Panics
+///
+/// Panics if `range.end` is out of bounds of `dest`, or if `range.start > range.end`.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest = vec![1u8, 2, 3, 4, 5];
+/// let src = vec![10u8, 11];
+///
+/// let removed = splice::splice_replace_copy(&mut dest, 1..3, &src);
+///
+/// assert_eq!(dest, vec![1, 10, 11, 4, 5]);
+/// assert_eq!(removed, vec![2, 3]);
+/// ```
+pub fn splice_replace_copy<T: Copy>(dest: &mut Vec<T>, range: Range<usize>, src: &[T]) -> Vec<T> {
+    assert!(range.start <= range.end, "range start is after its end");
+    assert!(range.end <= dest.len(), "range is out of bounds of the Vec");
+
+    let range_len = range.end - range.start;
+    let src_len = src.len();
+
+    if src_len > range_len {
+        dest.reserve(src_len - range_len);
+    }
+
+    let mut removed: Vec<T> = Vec::with_capacity(range_len);
+
+    unsafe {
+        let dest_ptr = dest.as_mut_ptr();
+        let range_ptr = dest_ptr.add(range.start);
+
+        ptr::copy_nonoverlapping(range_ptr, removed.as_mut_ptr(), range_len);
+        removed.set_len(range_len);
+
+        let tail_ptr = dest_ptr.add(range.end);
+        let tail_len = dest.len() - range.end;
+        let new_tail_ptr = dest_ptr.add(range.start + src_len);
+
+        ptr::copy(tail_ptr, new_tail_ptr, tail_len);
+        ptr::copy_nonoverlapping(src.as_ptr(), range_ptr, src_len);
+
+        let new_len = dest.len() - range_len + src_len;
+        dest.set_len(new_len);
+    }
+
+    removed
+}
+
+/// Removes the elements in `range` from `dest`, clones the contents of `src` into their place,
+/// and returns the removed elements as a new Vec, leaving `src` intact.
+///
+/// If one of the calls to `T::clone` panics partway through, `dest` is left in a consistent
+/// state the same way [`splice_clone`] is: the tail, which has already been shifted to its final
+/// position, is moved back to close whatever part of the gap didn't get filled in, so no element
+/// of `dest` is read, dropped, or counted twice. The elements already moved into `removed` are
+/// unaffected either way.
+///
+/// # Panics
+///
+/// Panics if `range.end` is out of bounds of `dest`, or if `range.start > range.end`.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut dest: Vec<String> = vec!["one".into(), "two".into(), "three".into()];
+/// let src: Vec<String> = vec!["ten".into(), "eleven".into()];
+///
+/// let removed = splice::splice_replace_clone(&mut dest, 1..2, &src);
+///
+/// assert_eq!(dest, vec!["one", "ten", "eleven", "three"]);
+/// assert_eq!(removed, vec!["two"]);
+/// ```
+pub fn splice_replace_clone<T: Clone>(dest: &mut Vec<T>, range: Range<usize>, src: &[T]) -> Vec<T> {
+    assert!(range.start <= range.end, "range start is after its end");
+    assert!(range.end <= dest.len(), "range is out of bounds of the Vec");
+
+    let range_len = range.end - range.start;
+    let src_len = src.len();
+
+    if src_len > range_len {
+        dest.reserve(src_len - range_len);
+    }
+
+    let mut removed: Vec<T> = Vec::with_capacity(range_len);
+
+    // See the `Guard` in `try_splice_clone` for the rationale; this is the same scheme applied
+    // to the gap opened up for `src` here instead of the one opened by a plain insert.
+    struct Guard<T> {
+        dest: *mut Vec<T>,
+        index: usize,
+        gap: usize,
+        tail_len: usize,
+        written: usize,
+    }
+
+    impl<T> Drop for Guard<T> {
+        fn drop(&mut self) {
+            unsafe {
+                let dest = &mut *self.dest;
+                let dest_ptr = dest.as_mut_ptr().add(self.index);
+                let unfilled = self.gap - self.written;
+
+                if unfilled > 0 {
+                    let tail_ptr = dest_ptr.add(self.gap);
+                    let compact_ptr = dest_ptr.add(self.written);
+                    ptr::copy(tail_ptr, compact_ptr, self.tail_len);
+                }
+
+                dest.set_len(self.index + self.written + self.tail_len);
+            }
+        }
+    }
+
+    unsafe {
+        let dest_ptr = dest.as_mut_ptr();
+        let range_ptr = dest_ptr.add(range.start);
+
+        ptr::copy_nonoverlapping(range_ptr, removed.as_mut_ptr(), range_len);
+        removed.set_len(range_len);
+
+        let tail_ptr = dest_ptr.add(range.end);
+        let tail_len = dest.len() - range.end;
+        let new_tail_ptr = dest_ptr.add(range.start + src_len);
+
+        ptr::copy(tail_ptr, new_tail_ptr, tail_len);
+
+        let mut guard = Guard {
+            dest: dest as *mut Vec<T>,
+            index: range.start,
+            gap: src_len,
+            tail_len,
+            written: 0,
+        };
+
+        for (idx, elem) in src.iter().enumerate() {
+            ptr::write(range_ptr.offset(idx as isize), elem.clone());
+            guard.written = idx + 1;
+        }
+
+        let new_len = dest.len() - range_len + src_len;
+        mem::forget(guard);
+        dest.set_len(new_len);
+    }
+
+    removed
+}
+
+/// A `Clone`/`Drop` fixture shared by this crate's panic-safety tests.
+#[cfg(test)]
+mod track_fixture {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// `clone()` panics on its `panic_at`th call, counted across every `Track` sharing the same
+    /// `clones` counter. Every drop is recorded via the shared `drops` counter,
+    /// so a test can assert nothing was duplicated or leaked after a clone panics partway
+    /// through a splice.
+    pub struct Track {
+        drops: Rc<Cell<u32>>,
+        clones: Rc<Cell<u32>>,
+        panic_at: u32,
+    }
+
+    impl Track {
+        pub fn new(drops: &Rc<Cell<u32>>, clones: &Rc<Cell<u32>>, panic_at: u32) -> Self {
+            Track {
+                drops: drops.clone(),
+                clones: clones.clone(),
+                panic_at,
+            }
+        }
+    }
+
+    impl Clone for Track {
+        fn clone(&self) -> Self {
+            let n = self.clones.get() + 1;
+            self.clones.set(n);
+
+            if n == self.panic_at {
+                panic!("Track::clone panicking intentionally for test");
+            }
+
+            Track {
+                drops: self.drops.clone(),
+                clones: self.clones.clone(),
+                panic_at: self.panic_at,
+            }
+        }
+    }
+
+    impl Drop for Track {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
 }
 
 #[test]
@@ -182,3 +888,297 @@ fn splice_test() {
     assert_eq!(asdf, vec![1, 2, 5, 6, 3, 4]);
     assert!(qwop.is_empty());
 }
+
+#[test]
+fn splice_copy_slices_test() {
+    let mut asdf = vec![1u8, 2, 7, 8];
+    let mid = vec![3u8, 4];
+    let more = vec![5u8, 6];
+
+    splice_copy_slices(&mut asdf, 2, &[&mid, &more]);
+
+    assert_eq!(asdf, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn splice_clone_slices_test() {
+    let mut asdf: Vec<String> = vec!["asdf".into(), "qwop".into()];
+    let girp: Vec<String> = vec!["girp".into()];
+    let zxcv: Vec<String> = vec!["zxcv".into(), "thud".into()];
+
+    splice_clone_slices(&mut asdf, 1, &[&girp, &zxcv]);
+
+    assert_eq!(asdf, vec!["asdf", "girp", "zxcv", "thud", "qwop"]);
+}
+
+#[test]
+fn splice_clone_slices_panic_safety_test() {
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+    use track_fixture::Track;
+
+    let drops = Rc::new(Cell::new(0));
+    let clones = Rc::new(Cell::new(0));
+    let mk = || Track::new(&drops, &clones, 3);
+
+    let mut dest = vec![mk(), mk()];
+    let a = vec![mk(), mk()];
+    let b = vec![mk(), mk()];
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        splice_clone_slices(&mut dest, 1, &[&a, &b]);
+    }));
+
+    assert!(result.is_err());
+
+    // two clones succeeded (both from `a`) before the third call (the first from `b`) panicked,
+    // so dest should hold its two original elements plus those two clones.
+    assert_eq!(dest.len(), 4);
+
+    drop(dest);
+    drop(a);
+    drop(b);
+
+    // every `Track` that was ever constructed (the 2 dest originals, the 2+2 src originals, and
+    // the 2 clones that succeeded) must be dropped exactly once: no duplicates, no leaks.
+    assert_eq!(drops.get(), 8);
+}
+
+#[test]
+fn splice_copy_slices_empty_test() {
+    let mut asdf = vec![1u8, 2, 3, 4];
+    let empty: Vec<u8> = Vec::new();
+
+    splice_copy_slices(&mut asdf, 2, &[&empty, &empty]);
+
+    assert_eq!(asdf, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn splice_clone_panic_safety_test() {
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+    use track_fixture::Track;
+
+    let drops = Rc::new(Cell::new(0));
+    let clones = Rc::new(Cell::new(0));
+    let mk = || Track::new(&drops, &clones, 3);
+
+    let mut dest = vec![mk(), mk()];
+    let src = vec![mk(), mk(), mk(), mk()];
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        splice_clone(&mut dest, 1, &src);
+    }));
+
+    assert!(result.is_err());
+
+    // two clones succeeded before the third call panicked, so dest should hold its two
+    // original elements plus those two clones, with the tail compacted back into place.
+    assert_eq!(dest.len(), 4);
+
+    drop(dest);
+    drop(src);
+
+    // every `Track` that was ever constructed (the 2 dest originals, the 4 src originals, and
+    // the 2 clones that succeeded) must be dropped exactly once: no duplicates, no leaks.
+    assert_eq!(drops.get(), 8);
+}
+
+#[test]
+fn try_splice_copy_out_of_bounds_test() {
+    let mut dest = vec![1u8, 2, 3, 4];
+    let src = vec![5u8, 6];
+
+    let err = try_splice_copy(&mut dest, 10, &src).unwrap_err();
+
+    assert_eq!(err, SpliceError::IndexOutOfBounds { index: 10, len: 4 });
+}
+
+#[test]
+fn try_splice_copy_capacity_overflow_test() {
+    // A ZST Vec can reach `usize::MAX` elements without actually allocating anything, so this
+    // exercises the checked-arithmetic bounds check rather than an actual allocation failure.
+    let mut dest: Vec<()> = vec![(); usize::MAX];
+    let src: Vec<()> = vec![(), ()];
+
+    let err = try_splice_copy(&mut dest, 0, &src).unwrap_err();
+
+    assert_eq!(err, SpliceError::CapacityOverflow);
+}
+
+#[test]
+fn try_splice_clone_ok_test() {
+    let mut dest: Vec<String> = vec!["one".into(), "two".into()];
+    let src: Vec<String> = vec!["three".into(), "four".into()];
+
+    try_splice_clone(&mut dest, 1, &src).unwrap();
+
+    assert_eq!(dest, vec!["one", "three", "four", "two"]);
+}
+
+#[test]
+fn try_splice_clone_capacity_overflow_test() {
+    let mut dest: Vec<()> = vec![(); usize::MAX];
+    let src: Vec<()> = vec![(), ()];
+
+    let err = try_splice_clone(&mut dest, 0, &src).unwrap_err();
+
+    assert_eq!(err, SpliceError::CapacityOverflow);
+}
+
+#[test]
+fn try_splice_out_of_bounds_test() {
+    let mut dest = vec![1u8, 2, 3, 4];
+    let mut src = vec![5u8, 6];
+
+    let err = try_splice(&mut dest, 10, &mut src).unwrap_err();
+
+    assert_eq!(err, SpliceError::IndexOutOfBounds { index: 10, len: 4 });
+}
+
+#[test]
+fn try_splice_capacity_overflow_test() {
+    let mut dest: Vec<()> = vec![(); usize::MAX];
+    let mut src: Vec<()> = vec![(), ()];
+
+    let err = try_splice(&mut dest, 0, &mut src).unwrap_err();
+
+    assert_eq!(err, SpliceError::CapacityOverflow);
+    // the error was returned before anything was moved out of `src`.
+    assert_eq!(src.len(), 2);
+}
+
+#[test]
+fn splice_within_move_earlier_test() {
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+
+    splice_within(&mut v, 3..5, 1);
+
+    assert_eq!(v, vec![1, 4, 5, 2, 3, 6]);
+}
+
+#[test]
+fn splice_within_move_later_test() {
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+
+    splice_within(&mut v, 1..3, 4);
+
+    assert_eq!(v, vec![1, 4, 5, 6, 2, 3]);
+}
+
+#[test]
+fn splice_within_touching_ranges_test() {
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+
+    // src and the destination are adjacent, so this just swaps the two blocks.
+    splice_within(&mut v, 0..2, 2);
+
+    assert_eq!(v, vec![3, 4, 1, 2, 5, 6]);
+}
+
+#[test]
+fn splice_within_no_op_test() {
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+
+    splice_within(&mut v, 2..4, 2);
+
+    assert_eq!(v, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn splice_replace_test() {
+    let mut dest = vec![1, 2, 3, 4, 5];
+    let mut src = vec![10, 11];
+
+    let removed = splice_replace(&mut dest, 1..3, &mut src);
+
+    assert_eq!(dest, vec![1, 10, 11, 4, 5]);
+    assert_eq!(removed, vec![2, 3]);
+    assert!(src.is_empty());
+}
+
+#[test]
+fn splice_replace_zst_no_overflow_test() {
+    // A ZST Vec can reach `usize::MAX` elements without actually allocating anything, so the
+    // length arithmetic in `splice_replace` must not overflow even when `dest.len() + src.len()`
+    // would, despite the true result being small.
+    let mut dest: Vec<()> = vec![(); usize::MAX];
+    let mut src: Vec<()> = vec![(), ()];
+
+    let removed = splice_replace(&mut dest, 0..usize::MAX, &mut src);
+
+    assert_eq!(dest.len(), 2);
+    assert_eq!(removed.len(), usize::MAX);
+    assert!(src.is_empty());
+}
+
+#[test]
+fn splice_replace_shrinking_test() {
+    let mut dest = vec![1, 2, 3, 4, 5];
+    let mut src = vec![10];
+
+    let removed = splice_replace(&mut dest, 1..3, &mut src);
+
+    assert_eq!(dest, vec![1, 10, 4, 5]);
+    assert_eq!(removed, vec![2, 3]);
+    assert!(src.is_empty());
+}
+
+#[test]
+fn splice_replace_copy_test() {
+    let mut dest = vec![1u8, 2, 3, 4, 5];
+    let src = vec![10u8, 11, 12];
+
+    let removed = splice_replace_copy(&mut dest, 1..3, &src);
+
+    assert_eq!(dest, vec![1, 10, 11, 12, 4, 5]);
+    assert_eq!(removed, vec![2, 3]);
+    assert_eq!(src, vec![10, 11, 12]);
+}
+
+#[test]
+fn splice_replace_clone_test() {
+    let mut dest: Vec<String> = vec!["one".into(), "two".into(), "three".into()];
+    let src: Vec<String> = vec!["ten".into(), "eleven".into()];
+
+    let removed = splice_replace_clone(&mut dest, 1..2, &src);
+
+    assert_eq!(dest, vec!["one", "ten", "eleven", "three"]);
+    assert_eq!(removed, vec!["two"]);
+    assert_eq!(src, vec!["ten", "eleven"]);
+}
+
+#[test]
+fn splice_replace_clone_panic_safety_test() {
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+    use track_fixture::Track;
+
+    let drops = Rc::new(Cell::new(0));
+    let clones = Rc::new(Cell::new(0));
+    let mk = || Track::new(&drops, &clones, 3);
+
+    let mut dest = vec![mk(), mk(), mk()];
+    let src = vec![mk(), mk(), mk(), mk()];
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        splice_replace_clone(&mut dest, 1..2, &src);
+    }));
+
+    assert!(result.is_err());
+
+    // two clones succeeded before the third call panicked, so dest keeps its first element,
+    // the two successful clones, and the remaining tail element.
+    assert_eq!(dest.len(), 4);
+
+    drop(dest);
+    drop(src);
+
+    // every `Track` that was ever constructed (the 3 dest originals, the 4 src originals, and
+    // the 2 clones that succeeded) must be dropped exactly once: no duplicates, no leaks.
+    assert_eq!(drops.get(), 9);
+}